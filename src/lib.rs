@@ -7,10 +7,43 @@ use schemars::JsonSchema;
 /// Enum for managing different storage keys used within the smart contract.
 #[derive(BorshStorageKey, BorshSerialize)]
 enum StorageKey {
-    /// Storage key for mapping universities by account ID.
+    /// Legacy storage key of the account-keyed map; holds the prefix used by the old layout
+    /// so the versioned keys below serialize to different prefixes.
+    #[allow(dead_code)]
     UniversitiesAccounts,
-    /// Storage key for mapping universities by their names.
+    /// Legacy storage key of the name map; holds the prefix used by the old layout.
+    #[allow(dead_code)]
     UniversitiesByName,
+    /// Storage key for mapping universities by their generated id.
+    UniversitiesAccountsV2,
+    /// Storage key for mapping universities by their names.
+    UniversitiesByNameV2,
+    /// Storage key for the account-id secondary index mapping to a university id.
+    UniversitiesByAccountIdV1,
+}
+
+/// Roles that an account can hold in the registry's access-control list.
+#[derive(Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+#[derive(Clone, Debug, JsonSchema, PartialEq)]
+pub enum Role {
+    /// Full control, including managing the role registry.
+    Owner,
+    /// May register universities but cannot mutate roles.
+    Moderator,
+    /// No administrative privileges.
+    User,
+}
+
+/// An entry in the registry's access-control list, pairing an account with its role.
+#[derive(Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+#[derive(Clone, Debug, JsonSchema, PartialEq)]
+pub struct AccountRole {
+    /// The account the role is granted to.
+    pub account_id: String,
+    /// The role held by the account.
+    pub role: Role,
 }
 
 /// Struct representing a university with a name and associated account ID.
@@ -18,33 +51,143 @@ enum StorageKey {
 #[serde(crate = "near_sdk::serde")]
 #[derive(Clone, Debug, JsonSchema, PartialEq)]
 pub struct University {
+    /// The registry's immutable, deterministically generated primary key.
+    pub id: String,
     /// The name of the university.
     pub name: String,
-    /// The unique account ID associated with the university.
+    /// The NEAR account ID associated with the university; a mutable secondary attribute.
     pub account_id: String,
 }
 
+/// A single page of universities returned by [`UniversityRegistry::get_universities`].
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+#[derive(Clone, Debug, JsonSchema, PartialEq)]
+pub struct UniversitiesPage {
+    /// The universities in this page.
+    pub universities: Vec<University>,
+    /// The total number of universities matching the query, across all pages.
+    pub total: u64,
+}
+
+/// A university as stored before the registry gained a generated `id` primary key.
+#[derive(Clone, BorshDeserialize, BorshSerialize)]
+pub struct OldUniversity {
+    name: String,
+    account_id: String,
+}
+
+/// The previous on-chain layout of [`UniversityRegistry`], read by [`UniversityRegistry::migrate`].
+///
+/// This mirrors the pre-series baseline exactly: two maps keyed by account ID and name, and
+/// no `account_roles`. It is only ever deserialized from the raw contract state.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct OldUniversityRegistry {
+    universities_accounts: UnorderedMap<String, OldUniversity>,
+    universities_by_name: UnorderedMap<String, Vec<OldUniversity>>,
+}
+
 /// Main smart contract struct for managing university registration and lookups.
 #[near_bindgen]
 #[derive(BorshDeserialize, BorshSerialize)]
 pub struct UniversityRegistry {
-    /// A map of universities keyed by account ID.
+    /// A map of universities keyed by their generated id.
     universities_accounts: UnorderedMap<String, University>,
     /// A map of universities grouped by name.
     universities_by_name: UnorderedMap<String, Vec<University>>,
+    /// Secondary index mapping a university's account ID to its generated id.
+    universities_by_account_id: UnorderedMap<String, String>,
+    /// The access-control list granting accounts their administrative roles.
+    account_roles: Vec<AccountRole>,
+    /// Monotonic nonce mixed into id generation to keep generated ids unique.
+    id_nonce: u64,
 }
 
 impl Default for UniversityRegistry {
     fn default() -> Self {
         Self {
-            universities_accounts: UnorderedMap::new(StorageKey::UniversitiesAccounts),
-            universities_by_name: UnorderedMap::new(StorageKey::UniversitiesByName),
+            universities_accounts: UnorderedMap::new(StorageKey::UniversitiesAccountsV2),
+            universities_by_name: UnorderedMap::new(StorageKey::UniversitiesByNameV2),
+            universities_by_account_id: UnorderedMap::new(StorageKey::UniversitiesByAccountIdV1),
+            account_roles: vec![AccountRole {
+                account_id: env::current_account_id().to_string(),
+                role: Role::Owner,
+            }],
+            id_nonce: 0,
         }
     }
 }
 
 #[near_bindgen]
 impl UniversityRegistry {
+    /// Migrates the contract state from [`OldUniversityRegistry`] to the current,
+    /// version-suffixed storage layout.
+    ///
+    /// This reads the previous struct via [`env::state_read`], converts every entry into
+    /// its current shape, and reinserts it under the new versioned storage keys.
+    ///
+    /// # Invariant
+    ///
+    /// `migrate` must be deployed and called in the *same transaction* as the code upgrade
+    /// (deploy + `migrate` batched together), so no caller can observe a half-migrated state.
+    /// It panics if no prior state exists, since a fresh deployment should use [`Default`]
+    /// rather than migrating from nothing.
+    ///
+    /// The source layout read here is [`OldUniversityRegistry`] — the pre-series baseline with
+    /// two maps and no access-control list. `account_roles` is seeded fresh (the current account
+    /// as the sole `Owner`, exactly as [`Default`] does) rather than read from the old state.
+    #[private]
+    #[init(ignore_state)]
+    pub fn migrate() -> Self {
+        let old: OldUniversityRegistry =
+            env::state_read().expect("no prior state to migrate from");
+
+        let mut registry = Self {
+            universities_accounts: UnorderedMap::new(StorageKey::UniversitiesAccountsV2),
+            universities_by_name: UnorderedMap::new(StorageKey::UniversitiesByNameV2),
+            universities_by_account_id: UnorderedMap::new(StorageKey::UniversitiesByAccountIdV1),
+            account_roles: vec![AccountRole {
+                account_id: env::current_account_id().to_string(),
+                role: Role::Owner,
+            }],
+            id_nonce: 0,
+        };
+
+        // Re-key every entry from its account ID onto a freshly generated id, rebuilding
+        // the account-id secondary index.
+        for (account_id, old_university) in old.universities_accounts.iter() {
+            let id = registry.generate_id(&account_id);
+            let university = University {
+                id,
+                name: old_university.name,
+                account_id: account_id.clone(),
+            };
+            registry
+                .universities_accounts
+                .insert(&university.id, &university);
+            registry
+                .universities_by_account_id
+                .insert(&account_id, &university.id);
+        }
+
+        // Rebuild the name index from the old one, mapping each entry to its re-keyed record
+        // so the exact name grouping (including any duplicates) is preserved.
+        for (name, old_universities) in old.universities_by_name.iter() {
+            let universities: Vec<University> = old_universities
+                .iter()
+                .filter_map(|old_university| {
+                    registry
+                        .universities_by_account_id
+                        .get(&old_university.account_id)
+                        .and_then(|id| registry.universities_accounts.get(&id))
+                })
+                .collect();
+            registry.universities_by_name.insert(&name, &universities);
+        }
+
+        registry
+    }
+
     /// Adds a new university to the registry.
     ///
     /// # Arguments
@@ -58,26 +201,48 @@ impl UniversityRegistry {
     ///
     /// # Panics
     ///
-    /// Panics if the caller is not the contract owner or if the account ID already exists in the registry.
+    /// Panics if the caller is not an `Owner` or `Moderator`, or if the account ID already exists in the registry.
     pub fn add_university(&mut self, name: String, account_id: String) -> University {
+        self.require_can_register();
         require!(
-            env::signer_account_id() == env::current_account_id(),
-            "Permission denied"
-        );
-        require!(
-            self.universities_accounts.get(&account_id).is_none(),
+            self.universities_by_account_id.get(&account_id).is_none(),
             "Account already exists"
         );
 
-        let university = University { name, account_id };
+        let id = self.generate_id(&account_id);
+        let university = University {
+            id: id.clone(),
+            name,
+            account_id,
+        };
 
-        self.universities_accounts
-            .insert(&university.account_id, &university);
+        self.universities_accounts.insert(&id, &university);
+        self.universities_by_account_id
+            .insert(&university.account_id, &id);
         self.add_university_by_name(university.clone());
 
         university
     }
 
+    /// Generates a deterministic, immutable id for a university.
+    ///
+    /// The id is the hex-encoded SHA-256 of the account ID concatenated with the current
+    /// block timestamp and a monotonic nonce, so repeated registrations within one block
+    /// still receive distinct ids.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id` - The account ID the university is being registered with.
+    fn generate_id(&mut self, account_id: &str) -> String {
+        let nonce = self.id_nonce;
+        self.id_nonce += 1;
+        let seed = format!("{}{}{}", account_id, env::block_timestamp(), nonce);
+        env::sha256(seed.as_bytes())
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
     /// Internal helper function to add a university to the `universities_by_name` map.
     ///
     /// # Arguments
@@ -101,11 +266,64 @@ impl UniversityRegistry {
     ///
     /// # Returns
     ///
-    /// Returns a vector of tuples, where each tuple contains an account ID and the associated `University` struct.
+    /// Returns a vector of tuples, where each tuple contains a university's generated id and the associated `University` struct.
     pub fn get_all_universities(&self) -> Vec<(String, University)> {
         self.universities_accounts.to_vec()
     }
 
+    /// Retrieves a page of universities, optionally filtered by a name prefix.
+    ///
+    /// # Arguments
+    ///
+    /// * `from_index` - The number of matching universities to skip before the page begins.
+    /// * `limit` - The maximum number of universities to return in the page.
+    /// * `name_prefix` - When `Some`, only universities whose name starts with the prefix
+    ///   are considered, found by scanning the keys of the `universities_by_name` index.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `UniversitiesPage` with the requested slice and the total match count.
+    pub fn get_universities(
+        &self,
+        from_index: u64,
+        limit: u64,
+        name_prefix: Option<String>,
+    ) -> UniversitiesPage {
+        match name_prefix {
+            None => {
+                let total = self.universities_accounts.len();
+                let universities = self
+                    .universities_accounts
+                    .values()
+                    .skip(from_index as usize)
+                    .take(limit as usize)
+                    .collect();
+                UniversitiesPage {
+                    universities,
+                    total,
+                }
+            }
+            Some(prefix) => {
+                let matched: Vec<University> = self
+                    .universities_by_name
+                    .iter()
+                    .filter(|(name, _)| name.starts_with(&prefix))
+                    .flat_map(|(_, universities)| universities)
+                    .collect();
+                let total = matched.len() as u64;
+                let universities = matched
+                    .into_iter()
+                    .skip(from_index as usize)
+                    .take(limit as usize)
+                    .collect();
+                UniversitiesPage {
+                    universities,
+                    total,
+                }
+            }
+        }
+    }
+
     /// Retrieves universities by a given name.
     ///
     /// # Arguments
@@ -122,7 +340,20 @@ impl UniversityRegistry {
         }
     }
 
-    /// Retrieves a university by its account ID.
+    /// Retrieves a university by its generated id (the registry's primary key).
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The generated id of the university to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// Returns an `Option<University>`. `Some(University)` if found, or `None` if not found.
+    pub fn get_university_by_id(self, id: String) -> Option<University> {
+        self.universities_accounts.get(&id)
+    }
+
+    /// Retrieves a university by its account ID via the secondary index.
     ///
     /// # Arguments
     ///
@@ -132,7 +363,188 @@ impl UniversityRegistry {
     ///
     /// Returns an `Option<University>`. `Some(University)` if found, or `None` if not found.
     pub fn get_university_by_account_id(self, account_id: String) -> Option<University> {
-        self.universities_accounts.get(&account_id)
+        self.universities_by_account_id
+            .get(&account_id)
+            .and_then(|id| self.universities_accounts.get(&id))
+    }
+
+    /// Grants a role to an account, or updates the role if the account already has one.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id` - The account to grant the role to.
+    /// * `role` - The role to assign.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller is not an `Owner`.
+    pub fn add_account_role(&mut self, account_id: String, role: Role) {
+        self.require_owner();
+        match self
+            .account_roles
+            .iter_mut()
+            .find(|entry| entry.account_id == account_id)
+        {
+            Some(entry) => entry.role = role,
+            None => self.account_roles.push(AccountRole { account_id, role }),
+        }
+    }
+
+    /// Revokes any role held by an account.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id` - The account whose role should be removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller is not an `Owner`, or if the call would remove the last `Owner`
+    /// and leave the registry with no one able to manage roles.
+    pub fn remove_account_role(&mut self, account_id: String) {
+        self.require_owner();
+        let owners = self
+            .account_roles
+            .iter()
+            .filter(|entry| entry.role == Role::Owner)
+            .count();
+        require!(
+            !(owners == 1
+                && matches!(self.get_role(&account_id), Some(Role::Owner))),
+            "Cannot remove the last Owner"
+        );
+        self.account_roles
+            .retain(|entry| entry.account_id != account_id);
+    }
+
+    /// Lists every account role currently stored in the registry.
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of `AccountRole` entries.
+    pub fn list_account_roles(&self) -> Vec<AccountRole> {
+        self.account_roles.clone()
+    }
+
+    /// Looks up the role held by an account, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id` - The account to look up.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(Role)` if the account holds a role, or `None` otherwise.
+    fn get_role(&self, account_id: &str) -> Option<Role> {
+        self.account_roles
+            .iter()
+            .find(|entry| entry.account_id == account_id)
+            .map(|entry| entry.role.clone())
+    }
+
+    /// Updates a university's name, keeping the `universities_by_name` index consistent.
+    ///
+    /// The entry is pulled out of its old name's vector (dropping the name key if that
+    /// vector becomes empty) and pushed into the new name's vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id` - The account ID identifying the university to update.
+    /// * `new_name` - The university's new name.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `University`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller lacks registration rights or no university has the account ID.
+    pub fn update_university(&mut self, account_id: String, new_name: String) -> University {
+        self.require_can_register();
+        let id = self
+            .universities_by_account_id
+            .get(&account_id)
+            .expect("University not found");
+        let mut university = self
+            .universities_accounts
+            .get(&id)
+            .expect("University not found");
+
+        self.remove_university_by_name(&university.name, &account_id);
+        university.name = new_name;
+        self.universities_accounts.insert(&id, &university);
+        self.add_university_by_name(university.clone());
+
+        university
+    }
+
+    /// Removes a university from the registry, keeping every index consistent.
+    ///
+    /// The entry is deleted from `universities_accounts` and the account-id index, and
+    /// spliced out of its name's vector in `universities_by_name`.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id` - The account ID identifying the university to remove.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the caller lacks registration rights or no university has the account ID.
+    pub fn remove_university(&mut self, account_id: String) {
+        self.require_can_register();
+        let id = self
+            .universities_by_account_id
+            .get(&account_id)
+            .expect("University not found");
+        let university = self
+            .universities_accounts
+            .get(&id)
+            .expect("University not found");
+
+        self.universities_accounts.remove(&id);
+        self.universities_by_account_id.remove(&account_id);
+        self.remove_university_by_name(&university.name, &account_id);
+    }
+
+    /// Internal helper that splices the university with `account_id` out of the named
+    /// vector, deleting the name key entirely once its vector becomes empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name whose vector the university should be removed from.
+    /// * `account_id` - The account ID of the university to splice out.
+    fn remove_university_by_name(&mut self, name: &str, account_id: &str) {
+        let name = name.to_string();
+        if let Some(mut universities) = self.universities_by_name.get(&name) {
+            universities.retain(|university| university.account_id != account_id);
+            if universities.is_empty() {
+                self.universities_by_name.remove(&name);
+            } else {
+                self.universities_by_name.insert(&name, &universities);
+            }
+        }
+    }
+
+    /// Internal helper that panics unless the caller may register universities
+    /// (an `Owner` or `Moderator`).
+    fn require_can_register(&self) {
+        require!(
+            matches!(
+                self.get_role(&env::predecessor_account_id().to_string()),
+                Some(Role::Owner) | Some(Role::Moderator)
+            ),
+            "Permission denied"
+        );
+    }
+
+    /// Internal helper that panics unless the caller is an `Owner`.
+    fn require_owner(&self) {
+        require!(
+            matches!(
+                self.get_role(&env::predecessor_account_id().to_string()),
+                Some(Role::Owner)
+            ),
+            "Permission denied"
+        );
     }
 }
 
@@ -147,8 +559,14 @@ mod tests {
     #[test]
     fn add_university() {
         let mut contract = UniversityRegistry {
-            universities_accounts: UnorderedMap::new(StorageKey::UniversitiesAccounts),
-            universities_by_name: UnorderedMap::new(StorageKey::UniversitiesByName),
+            universities_accounts: UnorderedMap::new(StorageKey::UniversitiesAccountsV2),
+            universities_by_name: UnorderedMap::new(StorageKey::UniversitiesByNameV2),
+            universities_by_account_id: UnorderedMap::new(StorageKey::UniversitiesByAccountIdV1),
+            account_roles: vec![AccountRole {
+                account_id: "admin".to_string(),
+                role: Role::Owner,
+            }],
+            id_nonce: 0,
         };
         set_context_as_admin();
 
@@ -165,12 +583,19 @@ mod tests {
     #[test]
     fn add_university_by_name() {
         let mut contract = UniversityRegistry {
-            universities_accounts: UnorderedMap::new(StorageKey::UniversitiesAccounts),
-            universities_by_name: UnorderedMap::new(StorageKey::UniversitiesByName),
+            universities_accounts: UnorderedMap::new(StorageKey::UniversitiesAccountsV2),
+            universities_by_name: UnorderedMap::new(StorageKey::UniversitiesByNameV2),
+            universities_by_account_id: UnorderedMap::new(StorageKey::UniversitiesByAccountIdV1),
+            account_roles: vec![AccountRole {
+                account_id: "admin".to_string(),
+                role: Role::Owner,
+            }],
+            id_nonce: 0,
         };
         set_context_as_admin();
 
         contract.add_university_by_name(University {
+            id: "uni_id_hash".to_string(),
             name: "UMA".to_string(),
             account_id: "uni_id".parse().unwrap(),
         });
@@ -185,8 +610,14 @@ mod tests {
     #[should_panic]
     fn panics_on_permissions() {
         let mut contract = UniversityRegistry {
-            universities_accounts: UnorderedMap::new(StorageKey::UniversitiesAccounts),
-            universities_by_name: UnorderedMap::new(StorageKey::UniversitiesByName),
+            universities_accounts: UnorderedMap::new(StorageKey::UniversitiesAccountsV2),
+            universities_by_name: UnorderedMap::new(StorageKey::UniversitiesByNameV2),
+            universities_by_account_id: UnorderedMap::new(StorageKey::UniversitiesByAccountIdV1),
+            account_roles: vec![AccountRole {
+                account_id: "admin".to_string(),
+                role: Role::Owner,
+            }],
+            id_nonce: 0,
         };
         set_context_as_user();
 
@@ -198,13 +629,134 @@ mod tests {
     #[should_panic]
     fn panics_on_duplicate() {
         let mut contract = UniversityRegistry {
-            universities_accounts: UnorderedMap::new(StorageKey::UniversitiesAccounts),
-            universities_by_name: UnorderedMap::new(StorageKey::UniversitiesByName),
+            universities_accounts: UnorderedMap::new(StorageKey::UniversitiesAccountsV2),
+            universities_by_name: UnorderedMap::new(StorageKey::UniversitiesByNameV2),
+            universities_by_account_id: UnorderedMap::new(StorageKey::UniversitiesByAccountIdV1),
+            account_roles: vec![AccountRole {
+                account_id: "admin".to_string(),
+                role: Role::Owner,
+            }],
+            id_nonce: 0,
+        };
+        set_context_as_admin();
+
+        contract.add_university("UMA".to_string(), "uni_id".parse().unwrap());
+        contract.add_university("UMA".to_string(), "uni_id".parse().unwrap());
+    }
+
+    /// Test that `get_universities` paginates and filters by name prefix.
+    #[test]
+    fn get_universities_paginates_and_filters() {
+        let mut contract = UniversityRegistry {
+            universities_accounts: UnorderedMap::new(StorageKey::UniversitiesAccountsV2),
+            universities_by_name: UnorderedMap::new(StorageKey::UniversitiesByNameV2),
+            universities_by_account_id: UnorderedMap::new(StorageKey::UniversitiesByAccountIdV1),
+            account_roles: vec![AccountRole {
+                account_id: "admin".to_string(),
+                role: Role::Owner,
+            }],
+            id_nonce: 0,
+        };
+        set_context_as_admin();
+
+        contract.add_university("UMA".to_string(), "uma".parse().unwrap());
+        contract.add_university("UGR".to_string(), "ugr".parse().unwrap());
+        contract.add_university("UCM".to_string(), "ucm".parse().unwrap());
+
+        let page = contract.get_universities(0, 2, None);
+        assert_eq!(3, page.total);
+        assert_eq!(2, page.universities.len());
+
+        let filtered = contract.get_universities(0, 10, Some("U".to_string()));
+        assert_eq!(3, filtered.total);
+
+        let prefixed = contract.get_universities(0, 10, Some("UG".to_string()));
+        assert_eq!(1, prefixed.total);
+        assert_eq!("UGR".to_string(), prefixed.universities[0].name);
+    }
+
+    /// Test that updating a name moves the entry and deletes an emptied name key.
+    #[test]
+    fn update_university_moves_name_index() {
+        let mut contract = UniversityRegistry {
+            universities_accounts: UnorderedMap::new(StorageKey::UniversitiesAccountsV2),
+            universities_by_name: UnorderedMap::new(StorageKey::UniversitiesByNameV2),
+            universities_by_account_id: UnorderedMap::new(StorageKey::UniversitiesByAccountIdV1),
+            account_roles: vec![AccountRole {
+                account_id: "admin".to_string(),
+                role: Role::Owner,
+            }],
+            id_nonce: 0,
         };
         set_context_as_admin();
 
         contract.add_university("UMA".to_string(), "uni_id".parse().unwrap());
+        contract.update_university("uni_id".parse().unwrap(), "UGR".to_string());
+
+        assert!(contract.universities_by_name.get(&"UMA".to_string()).is_none());
+        assert!(contract
+            .get_universities(0, 10, Some("UMA".to_string()))
+            .universities
+            .is_empty());
+        let moved = contract
+            .get_universities(0, 10, Some("UGR".to_string()))
+            .universities;
+        assert_eq!(1, moved.len());
+        assert_eq!("uni_id".to_string(), moved[0].account_id);
+    }
+
+    /// Test that removing a university cleans up an emptied name vector.
+    #[test]
+    fn remove_university_cleans_up_empty_name() {
+        let mut contract = UniversityRegistry {
+            universities_accounts: UnorderedMap::new(StorageKey::UniversitiesAccountsV2),
+            universities_by_name: UnorderedMap::new(StorageKey::UniversitiesByNameV2),
+            universities_by_account_id: UnorderedMap::new(StorageKey::UniversitiesByAccountIdV1),
+            account_roles: vec![AccountRole {
+                account_id: "admin".to_string(),
+                role: Role::Owner,
+            }],
+            id_nonce: 0,
+        };
+        set_context_as_admin();
+
         contract.add_university("UMA".to_string(), "uni_id".parse().unwrap());
+        contract.remove_university("uni_id".parse().unwrap());
+
+        assert!(contract
+            .get_university_by_account_id("uni_id".parse().unwrap())
+            .is_none());
+        assert!(contract.universities_by_name.get(&"UMA".to_string()).is_none());
+        assert!(contract
+            .get_universities(0, 10, Some("UMA".to_string()))
+            .universities
+            .is_empty());
+    }
+
+    /// Test that removing one of several universities sharing a name leaves the rest intact.
+    #[test]
+    fn remove_university_with_shared_name() {
+        let mut contract = UniversityRegistry {
+            universities_accounts: UnorderedMap::new(StorageKey::UniversitiesAccountsV2),
+            universities_by_name: UnorderedMap::new(StorageKey::UniversitiesByNameV2),
+            universities_by_account_id: UnorderedMap::new(StorageKey::UniversitiesByAccountIdV1),
+            account_roles: vec![AccountRole {
+                account_id: "admin".to_string(),
+                role: Role::Owner,
+            }],
+            id_nonce: 0,
+        };
+        set_context_as_admin();
+
+        contract.add_university("UMA".to_string(), "first".parse().unwrap());
+        contract.add_university("UMA".to_string(), "second".parse().unwrap());
+        contract.remove_university("first".parse().unwrap());
+
+        let remaining = contract
+            .get_universities(0, 10, Some("UMA".to_string()))
+            .universities;
+        assert_eq!(1, remaining.len());
+        assert_eq!("second".to_string(), remaining[0].account_id);
     }
 
     /// Sets the testing environment context as an admin account.
@@ -212,6 +764,7 @@ mod tests {
         let mut builder = VMContextBuilder::new();
         builder.current_account_id("admin".parse().unwrap());
         builder.signer_account_id("admin".parse().unwrap());
+        builder.predecessor_account_id("admin".parse().unwrap());
         testing_env!(builder.build());
     }
 
@@ -26,12 +26,9 @@ async fn test_basics_on(contract_wasm: &[u8]) -> Result<(), Box<dyn std::error::
     .await?
     .json::<University>()?;
 
-    let expected_university = University {
-        name: "UMA".to_string(),
-        account_id: "admin".to_string(),
-    };
+    assert_eq!("UMA".to_string(), university_json.name);
+    assert_eq!("admin".to_string(), university_json.account_id);
+    assert!(!university_json.id.is_empty());
 
-    assert_eq!(expected_university, university_json);
-    
     Ok(())
 }